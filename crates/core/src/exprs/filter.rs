@@ -0,0 +1,205 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use anyhow::anyhow;
+use std::str::FromStr;
+
+use crate::exprs::HudiOperator;
+
+/// The right-hand side value(s) of a [Filter].
+///
+/// Most operators compare against a single value, but set-membership
+/// operators such as [HudiOperator::In] and [HudiOperator::NotIn] compare
+/// against multiple values at once, and the range operator
+/// [HudiOperator::Between] compares against a low/high pair.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Single(String),
+    Multiple(Vec<String>),
+    Range(String, String),
+}
+
+/// A filter expression that represents a comparison between a field and one or more values,
+/// as parsed from a query engine's filter pushdown (e.g. `date > '2023-01-01'`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    pub field_name: String,
+    pub operator: HudiOperator,
+    pub value: FilterValue,
+}
+
+impl TryFrom<(&str, &str, &str)> for Filter {
+    type Error = anyhow::Error;
+
+    fn try_from((field_name, operator, value): (&str, &str, &str)) -> anyhow::Result<Self> {
+        let operator = HudiOperator::from_str(operator)?;
+        if operator.is_set_operator() || operator.is_range_operator() {
+            return Err(anyhow!(
+                "Operator {} expects multiple values - use a different Filter::try_from overload.",
+                operator
+            ));
+        }
+        Ok(Filter {
+            field_name: field_name.to_string(),
+            operator,
+            value: FilterValue::Single(value.to_string()),
+        })
+    }
+}
+
+impl TryFrom<(&str, &str, &str, &str)> for Filter {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        (field_name, operator, low, high): (&str, &str, &str, &str),
+    ) -> anyhow::Result<Self> {
+        let operator = HudiOperator::from_str(operator)?;
+        if !operator.is_range_operator() {
+            return Err(anyhow!(
+                "Operator {} does not take a low/high range - use a different Filter::try_from overload.",
+                operator
+            ));
+        }
+        Ok(Filter {
+            field_name: field_name.to_string(),
+            operator,
+            value: FilterValue::Range(low.to_string(), high.to_string()),
+        })
+    }
+}
+
+impl TryFrom<(&str, &str, &[&str])> for Filter {
+    type Error = anyhow::Error;
+
+    fn try_from((field_name, operator, values): (&str, &str, &[&str])) -> anyhow::Result<Self> {
+        let operator = HudiOperator::from_str(operator)?;
+        if !operator.is_set_operator() {
+            return Err(anyhow!(
+                "Operator {} expects a different number of values - use a different Filter::try_from overload.",
+                operator
+            ));
+        }
+        Ok(Filter {
+            field_name: field_name.to_string(),
+            operator,
+            value: FilterValue::Multiple(values.iter().map(|v| v.to_string()).collect()),
+        })
+    }
+}
+
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    /// Parses a filter expression such as `date > 2023-01-01` or, for the range operator,
+    /// `count BETWEEN 10 AND 100`.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        match tokens.as_slice() {
+            [field_name, operator, low, and, high] if and.eq_ignore_ascii_case("AND") => {
+                Filter::try_from((*field_name, *operator, *low, *high))
+            }
+            [field_name, operator, value] => Filter::try_from((*field_name, *operator, *value)),
+            _ => Err(anyhow!("Unable to parse filter expression: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_try_from_single_value() {
+        let filter = Filter::try_from(("date", ">", "2023-01-01")).unwrap();
+        assert_eq!(filter.field_name, "date");
+        assert_eq!(filter.operator, HudiOperator::Gt);
+        assert_eq!(filter.value, FilterValue::Single("2023-01-01".to_string()));
+    }
+
+    #[test]
+    fn test_filter_try_from_set_value() {
+        let values = ["A", "B", "C"];
+        let filter = Filter::try_from(("category", "IN", values.as_slice())).unwrap();
+        assert_eq!(filter.field_name, "category");
+        assert_eq!(filter.operator, HudiOperator::In);
+        assert_eq!(
+            filter.value,
+            FilterValue::Multiple(vec!["A".to_string(), "B".to_string(), "C".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_filter_try_from_single_value_rejects_set_operator() {
+        let result = Filter::try_from(("category", "IN", "A"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_try_from_set_value_rejects_scalar_operator() {
+        let values = ["A"];
+        let result = Filter::try_from(("category", "=", values.as_slice()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_try_from_range_value() {
+        let filter = Filter::try_from(("count", "BETWEEN", "10", "100")).unwrap();
+        assert_eq!(filter.field_name, "count");
+        assert_eq!(filter.operator, HudiOperator::Between);
+        assert_eq!(
+            filter.value,
+            FilterValue::Range("10".to_string(), "100".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_try_from_range_value_rejects_scalar_operator() {
+        let result = Filter::try_from(("count", "=", "10", "100"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_try_from_single_value_rejects_range_operator() {
+        let result = Filter::try_from(("count", "BETWEEN", "10"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_from_str_scalar() {
+        let filter = Filter::from_str("date > 2023-01-01").unwrap();
+        assert_eq!(filter.field_name, "date");
+        assert_eq!(filter.operator, HudiOperator::Gt);
+        assert_eq!(filter.value, FilterValue::Single("2023-01-01".to_string()));
+    }
+
+    #[test]
+    fn test_filter_from_str_between() {
+        let filter = Filter::from_str("count BETWEEN 10 AND 100").unwrap();
+        assert_eq!(filter.field_name, "count");
+        assert_eq!(filter.operator, HudiOperator::Between);
+        assert_eq!(
+            filter.value,
+            FilterValue::Range("10".to_string(), "100".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_from_str_invalid() {
+        assert!(Filter::from_str("not a valid filter").is_err());
+    }
+}
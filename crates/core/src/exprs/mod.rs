@@ -35,6 +35,12 @@ pub enum HudiOperator {
     Lte,
     Gt,
     Gte,
+    In,
+    NotIn,
+    Between,
+    StartsWith,
+    Contains,
+    Matches,
 }
 
 impl Display for HudiOperator {
@@ -47,20 +53,57 @@ impl Display for HudiOperator {
             HudiOperator::Lte => write!(f, "<="),
             HudiOperator::Gt => write!(f, ">"),
             HudiOperator::Gte => write!(f, ">="),
+            // Set Operators
+            HudiOperator::In => write!(f, "IN"),
+            HudiOperator::NotIn => write!(f, "NOT IN"),
+            // Range Operators
+            HudiOperator::Between => write!(f, "BETWEEN"),
+            // String Pattern Operators
+            HudiOperator::StartsWith => write!(f, "STARTSWITH"),
+            HudiOperator::Contains => write!(f, "CONTAINS"),
+            HudiOperator::Matches => write!(f, "MATCHES"),
         }
     }
 }
 
 // TODO: Add more operators
 impl HudiOperator {
-    pub const TOKEN_OP_PAIRS: [(&'static str, HudiOperator); 6] = [
+    pub const TOKEN_OP_PAIRS: [(&'static str, HudiOperator); 12] = [
         ("=", HudiOperator::Eq),
         ("!=", HudiOperator::Ne),
         ("<", HudiOperator::Lt),
         ("<=", HudiOperator::Lte),
         (">", HudiOperator::Gt),
         (">=", HudiOperator::Gte),
+        ("IN", HudiOperator::In),
+        ("NOT IN", HudiOperator::NotIn),
+        ("BETWEEN", HudiOperator::Between),
+        ("STARTSWITH", HudiOperator::StartsWith),
+        ("CONTAINS", HudiOperator::Contains),
+        ("MATCHES", HudiOperator::Matches),
     ];
+
+    /// Returns `true` if the operator expects a set of values (e.g. `IN`, `NOT IN`)
+    /// rather than a single scalar value.
+    pub fn is_set_operator(&self) -> bool {
+        matches!(self, HudiOperator::In | HudiOperator::NotIn)
+    }
+
+    /// Returns `true` if the operator expects a low/high bound pair (e.g. `BETWEEN`)
+    /// rather than a single scalar value.
+    pub fn is_range_operator(&self) -> bool {
+        matches!(self, HudiOperator::Between)
+    }
+
+    /// Returns `true` if the operator matches against a `Utf8`/`LargeUtf8` field's raw
+    /// string value (e.g. `StartsWith`, `Contains`, `Matches`) rather than comparing casted
+    /// scalars with `arrow_ord::cmp`.
+    pub fn is_string_pattern_operator(&self) -> bool {
+        matches!(
+            self,
+            HudiOperator::StartsWith | HudiOperator::Contains | HudiOperator::Matches
+        )
+    }
 }
 
 impl FromStr for HudiOperator {
@@ -92,6 +135,47 @@ mod tests {
         assert_eq!(HudiOperator::from_str("<=").unwrap(), HudiOperator::Lte);
         assert_eq!(HudiOperator::from_str(">").unwrap(), HudiOperator::Gt);
         assert_eq!(HudiOperator::from_str(">=").unwrap(), HudiOperator::Gte);
+        assert_eq!(HudiOperator::from_str("IN").unwrap(), HudiOperator::In);
+        assert_eq!(HudiOperator::from_str("not in").unwrap(), HudiOperator::NotIn);
+        assert_eq!(
+            HudiOperator::from_str("between").unwrap(),
+            HudiOperator::Between
+        );
+        assert_eq!(
+            HudiOperator::from_str("startswith").unwrap(),
+            HudiOperator::StartsWith
+        );
+        assert_eq!(
+            HudiOperator::from_str("CONTAINS").unwrap(),
+            HudiOperator::Contains
+        );
+        assert_eq!(
+            HudiOperator::from_str("matches").unwrap(),
+            HudiOperator::Matches
+        );
         assert!(HudiOperator::from_str("??").is_err());
     }
+
+    #[test]
+    fn test_operator_is_set_operator() {
+        assert!(HudiOperator::In.is_set_operator());
+        assert!(HudiOperator::NotIn.is_set_operator());
+        assert!(!HudiOperator::Eq.is_set_operator());
+        assert!(!HudiOperator::Between.is_set_operator());
+    }
+
+    #[test]
+    fn test_operator_is_range_operator() {
+        assert!(HudiOperator::Between.is_range_operator());
+        assert!(!HudiOperator::Eq.is_range_operator());
+        assert!(!HudiOperator::In.is_range_operator());
+    }
+
+    #[test]
+    fn test_operator_is_string_pattern_operator() {
+        assert!(HudiOperator::StartsWith.is_string_pattern_operator());
+        assert!(HudiOperator::Contains.is_string_pattern_operator());
+        assert!(HudiOperator::Matches.is_string_pattern_operator());
+        assert!(!HudiOperator::Eq.is_string_pattern_operator());
+    }
 }
\ No newline at end of file
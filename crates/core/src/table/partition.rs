@@ -19,39 +19,81 @@
 use crate::config::table::HudiTableConfig;
 use crate::config::HudiConfigs;
 use crate::error::CoreError::{InvalidPartitionPath, Unsupported};
-use crate::exprs::filter::Filter;
-use crate::exprs::ExprOperator;
+use crate::exprs::filter::{Filter, FilterValue};
+use crate::exprs::HudiOperator;
 use crate::Result;
 
-use arrow_array::{ArrayRef, Scalar, StringArray};
+use arrow_array::{ArrayRef, LargeStringArray, Scalar, StringArray};
 use arrow_cast::{cast_with_options, CastOptions};
 use arrow_ord::cmp::{eq, gt, gt_eq, lt, lt_eq, neq};
 use arrow_schema::Schema;
 use arrow_schema::{DataType, Field};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use regex::Regex;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Characters kept unescaped when percent-encoding a partition path segment, mirroring what
+/// [PartitionPruner::parse_segments] expects to be able to [percent_encoding::percent_decode].
+const PATH_SEGMENT_SAFE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'=');
+
+/// A recursive boolean expression tree over [PartitionFilter] leaves, used by
+/// [PartitionPruner] to evaluate predicates that are not a flat conjunction
+/// (e.g. `date > X OR category = 'A'`).
+#[derive(Debug, Clone)]
+pub enum PartitionExpr {
+    And(Vec<PartitionExpr>),
+    Or(Vec<PartitionExpr>),
+    Not(Box<PartitionExpr>),
+    Leaf(PartitionFilter),
+}
+
 /// A partition pruner that filters partitions based on the partition path and its filters.
 #[derive(Debug, Clone)]
 pub struct PartitionPruner {
     schema: Arc<Schema>,
     is_hive_style: bool,
     is_url_encoded: bool,
-    and_filters: Vec<PartitionFilter>,
+    expr: PartitionExpr,
+    /// Known global min/max bounds per partition field, used by [Self::is_unsatisfiable] to
+    /// short-circuit listing when a filter provably excludes the whole table. Absent bounds
+    /// mean "cannot prove unsatisfiable", so pruning stays conservative.
+    stats: HashMap<String, (Scalar<ArrayRef>, Scalar<ArrayRef>)>,
 }
 
 impl PartitionPruner {
+    /// Creates a pruner from a flat list of filters that are implicitly AND-ed together.
     pub fn new(
         and_filters: &[Filter],
         partition_schema: &Schema,
         hudi_configs: &HudiConfigs,
     ) -> Result<Self> {
-        let and_filters = and_filters
+        let leaves = and_filters
             .iter()
-            .map(|filter| PartitionFilter::try_from((filter.clone(), partition_schema)))
-            .collect::<Result<Vec<PartitionFilter>>>()?;
+            .map(|filter| {
+                PartitionFilter::try_from((filter.clone(), partition_schema))
+                    .map(PartitionExpr::Leaf)
+            })
+            .collect::<Result<Vec<PartitionExpr>>>()?;
+
+        Ok(Self::new_with_expr(
+            PartitionExpr::And(leaves),
+            partition_schema,
+            hudi_configs,
+        ))
+    }
 
+    /// Creates a pruner from an arbitrary AND/OR/NOT expression tree.
+    pub fn new_with_expr(
+        expr: PartitionExpr,
+        partition_schema: &Schema,
+        hudi_configs: &HudiConfigs,
+    ) -> Self {
         let schema = Arc::new(partition_schema.clone());
         let is_hive_style: bool = hudi_configs
             .get_or_default(HudiTableConfig::IsHiveStylePartitioning)
@@ -59,12 +101,20 @@ impl PartitionPruner {
         let is_url_encoded: bool = hudi_configs
             .get_or_default(HudiTableConfig::IsPartitionPathUrlencoded)
             .to();
-        Ok(PartitionPruner {
+        PartitionPruner {
             schema,
             is_hive_style,
             is_url_encoded,
-            and_filters,
-        })
+            expr,
+            stats: HashMap::new(),
+        }
+    }
+
+    /// Attaches known global min/max bounds per partition field, e.g. sourced from table
+    /// metadata, enabling [Self::is_unsatisfiable].
+    pub fn with_stats(mut self, stats: HashMap<String, (Scalar<ArrayRef>, Scalar<ArrayRef>)>) -> Self {
+        self.stats = stats;
+        self
     }
 
     /// Creates an empty partition pruner that does not filter any partitions.
@@ -73,13 +123,14 @@ impl PartitionPruner {
             schema: Arc::new(Schema::empty()),
             is_hive_style: false,
             is_url_encoded: false,
-            and_filters: Vec::new(),
+            expr: PartitionExpr::And(Vec::new()),
+            stats: HashMap::new(),
         }
     }
 
     /// Returns `true` if the partition pruner does not have any filters.
     pub fn is_empty(&self) -> bool {
-        self.and_filters.is_empty()
+        matches!(&self.expr, PartitionExpr::And(exprs) if exprs.is_empty())
     }
 
     /// Returns `true` if the partition path should be included based on the filters.
@@ -89,28 +140,407 @@ impl PartitionPruner {
             Err(_) => return true, // Include the partition regardless of parsing error
         };
 
-        self.and_filters.iter().all(|filter| {
-            match segments.get(filter.field.name()) {
-                Some(segment_value) => {
+        // `None` means the expression couldn't be decided (e.g. a missing field or a
+        // comparison error below); default to including the partition rather than dropping it.
+        Self::evaluate_expr(&self.expr, &segments).unwrap_or(true)
+    }
+
+    /// Evaluates `expr` to `Some(bool)` when its truth value is decided, or `None` when some
+    /// leaf couldn't be decided (missing field, comparison error). `None` must propagate
+    /// through [PartitionExpr::Not] unchanged rather than being negated, so that a
+    /// conservative "include" at a leaf stays "include" no matter how deeply it's negated.
+    fn evaluate_expr(
+        expr: &PartitionExpr,
+        segments: &HashMap<String, Scalar<ArrayRef>>,
+    ) -> Option<bool> {
+        match expr {
+            PartitionExpr::And(exprs) => {
+                let mut undecided = false;
+                for expr in exprs {
+                    match Self::evaluate_expr(expr, segments) {
+                        Some(false) => return Some(false),
+                        Some(true) => {}
+                        None => undecided = true,
+                    }
+                }
+                if undecided {
+                    None
+                } else {
+                    Some(true)
+                }
+            }
+            PartitionExpr::Or(exprs) => {
+                let mut undecided = false;
+                for expr in exprs {
+                    match Self::evaluate_expr(expr, segments) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => undecided = true,
+                    }
+                }
+                if undecided {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            PartitionExpr::Not(expr) => Self::evaluate_expr(expr, segments).map(|b| !b),
+            PartitionExpr::Leaf(filter) => Self::evaluate_filter(filter, segments),
+        }
+    }
+
+    fn evaluate_filter(
+        filter: &PartitionFilter,
+        segments: &HashMap<String, Scalar<ArrayRef>>,
+    ) -> Option<bool> {
+        match segments.get(filter.field.name()) {
+            Some(segment_value) => match filter.operator {
+                HudiOperator::In | HudiOperator::NotIn => {
+                    let mut undecided = false;
+                    let any_match = filter.values.iter().any(|value| {
+                        eq(segment_value, value).map(|scalar| scalar.value(0)).unwrap_or_else(|_| {
+                            undecided = true;
+                            false
+                        })
+                    });
+                    if undecided {
+                        None // Comparison error: leave undecided, same as every other operator
+                    } else {
+                        Some(if filter.operator == HudiOperator::In {
+                            any_match
+                        } else {
+                            !any_match
+                        })
+                    }
+                }
+                HudiOperator::Between => {
+                    let low_ok = gt_eq(segment_value, &filter.values[0]).map(|scalar| scalar.value(0));
+                    let high_ok = lt_eq(segment_value, &filter.values[1]).map(|scalar| scalar.value(0));
+                    match (low_ok, high_ok) {
+                        (Ok(low_ok), Ok(high_ok)) => Some(low_ok && high_ok),
+                        _ => None, // Comparison error: leave undecided
+                    }
+                }
+                HudiOperator::StartsWith | HudiOperator::Contains | HudiOperator::Matches => {
+                    match Self::segment_as_str(segment_value) {
+                        Some(segment_str) => Some(match filter.operator {
+                            HudiOperator::StartsWith => {
+                                segment_str.starts_with(filter.raw_values[0].as_str())
+                            }
+                            HudiOperator::Contains => {
+                                segment_str.contains(filter.raw_values[0].as_str())
+                            }
+                            HudiOperator::Matches => filter
+                                .regex
+                                .as_ref()
+                                .map(|regex| regex.is_match(&segment_str))
+                                .unwrap_or(true), // Include the partition when comparison error occurs
+                            _ => unreachable!(),
+                        }),
+                        None => None, // Segment isn't a string: leave undecided
+                    }
+                }
+                _ => {
                     let comparison_result = match filter.operator {
-                        ExprOperator::Eq => eq(segment_value, &filter.value),
-                        ExprOperator::Ne => neq(segment_value, &filter.value),
-                        ExprOperator::Lt => lt(segment_value, &filter.value),
-                        ExprOperator::Lte => lt_eq(segment_value, &filter.value),
-                        ExprOperator::Gt => gt(segment_value, &filter.value),
-                        ExprOperator::Gte => gt_eq(segment_value, &filter.value),
+                        HudiOperator::Eq => eq(segment_value, &filter.values[0]),
+                        HudiOperator::Ne => neq(segment_value, &filter.values[0]),
+                        HudiOperator::Lt => lt(segment_value, &filter.values[0]),
+                        HudiOperator::Lte => lt_eq(segment_value, &filter.values[0]),
+                        HudiOperator::Gt => gt(segment_value, &filter.values[0]),
+                        HudiOperator::Gte => gt_eq(segment_value, &filter.values[0]),
+                        HudiOperator::In
+                        | HudiOperator::NotIn
+                        | HudiOperator::Between
+                        | HudiOperator::StartsWith
+                        | HudiOperator::Contains
+                        | HudiOperator::Matches => {
+                            unreachable!()
+                        }
                     };
 
                     match comparison_result {
-                        Ok(scalar) => scalar.value(0),
-                        Err(_) => true, // Include the partition when comparison error occurs
+                        Ok(scalar) => Some(scalar.value(0)),
+                        Err(_) => None, // Comparison error: leave undecided
                     }
                 }
-                None => true, // Include the partition when filtering field does not match any field in the partition
+            },
+            None => None, // Filtering field does not match any field in the partition: leave undecided
+        }
+    }
+
+    /// Computes a listing path prefix from the leading partition fields that have an
+    /// equality filter, so the caller can prepend it to the table base path and have the
+    /// object store list only the matching sub-tree instead of every partition.
+    ///
+    /// Walks `schema` fields in order, stopping at the first field without an `Eq` filter.
+    /// Returns `None` when even the first field lacks one, or when the pruner's expression
+    /// is not a pure conjunction (an `Or`/`Not` makes a listing prefix unsound), so callers
+    /// fall back to listing the whole table.
+    pub fn listing_prefix(&self) -> Option<String> {
+        let leaves = Self::conjunctive_leaves(&self.expr)?;
+        let mut segments = Vec::new();
+
+        for field in self.schema.fields() {
+            let eq_value = leaves
+                .iter()
+                .find(|filter| {
+                    filter.operator == HudiOperator::Eq && filter.field.name() == field.name()
+                })
+                .and_then(|filter| filter.raw_values.first());
+
+            let Some(value) = eq_value else {
+                break;
+            };
+
+            let value = if self.is_url_encoded {
+                utf8_percent_encode(value, PATH_SEGMENT_SAFE).to_string()
+            } else {
+                value.clone()
+            };
+
+            segments.push(if self.is_hive_style {
+                format!("{}={}", field.name(), value)
+            } else {
+                value
+            });
+        }
+
+        if segments.is_empty() {
+            None
+        } else {
+            Some(segments.join("/"))
+        }
+    }
+
+    /// Flattens an expression into its leaves if it is a pure conjunction (nested `And`s of
+    /// `Leaf`s), returning `None` as soon as an `Or` or `Not` node is encountered.
+    fn conjunctive_leaves(expr: &PartitionExpr) -> Option<Vec<&PartitionFilter>> {
+        match expr {
+            PartitionExpr::Leaf(filter) => Some(vec![filter]),
+            PartitionExpr::And(exprs) => {
+                let mut leaves = Vec::new();
+                for expr in exprs {
+                    leaves.extend(Self::conjunctive_leaves(expr)?);
+                }
+                Some(leaves)
+            }
+            PartitionExpr::Or(_) | PartitionExpr::Not(_) => None,
+        }
+    }
+
+    /// Returns `true` if the pruner's filters provably exclude every partition in the table,
+    /// so the caller can skip listing entirely. Conservative: any bound it cannot prove
+    /// impossible (e.g. an unknown field, or a `Not`/string-pattern predicate) is treated as
+    /// satisfiable.
+    pub fn is_unsatisfiable(&self) -> bool {
+        Self::expr_is_unsatisfiable(&self.expr, &self.stats)
+    }
+
+    fn expr_is_unsatisfiable(
+        expr: &PartitionExpr,
+        stats: &HashMap<String, (Scalar<ArrayRef>, Scalar<ArrayRef>)>,
+    ) -> bool {
+        match expr {
+            PartitionExpr::Leaf(filter) => Self::leaf_is_unsatisfiable(filter, stats),
+            PartitionExpr::And(exprs) => {
+                exprs
+                    .iter()
+                    .any(|expr| Self::expr_is_unsatisfiable(expr, stats))
+                    || Self::and_leaves_contradict(exprs)
+            }
+            // An OR is unsatisfiable only if every one of its branches is.
+            PartitionExpr::Or(exprs) => {
+                !exprs.is_empty()
+                    && exprs
+                        .iter()
+                        .all(|expr| Self::expr_is_unsatisfiable(expr, stats))
+            }
+            // Proving a negation unsatisfiable would require reasoning about the whole
+            // complement of the inner predicate; stay conservative instead.
+            PartitionExpr::Not(_) => false,
+        }
+    }
+
+    /// Checks a single filter against the known global min/max for its field.
+    fn leaf_is_unsatisfiable(
+        filter: &PartitionFilter,
+        stats: &HashMap<String, (Scalar<ArrayRef>, Scalar<ArrayRef>)>,
+    ) -> bool {
+        // A BETWEEN whose low bound exceeds its high bound is empty regardless of stats.
+        if filter.operator == HudiOperator::Between
+            && gt(&filter.values[0], &filter.values[1])
+                .map(|s| s.value(0))
+                .unwrap_or(false)
+        {
+            return true;
+        }
+
+        let Some((min, max)) = stats.get(filter.field.name()) else {
+            return false; // No known bounds: cannot prove unsatisfiable.
+        };
+
+        match filter.operator {
+            HudiOperator::Eq => {
+                let below_min = lt(&filter.values[0], min)
+                    .map(|s| s.value(0))
+                    .unwrap_or(false);
+                let above_max = gt(&filter.values[0], max)
+                    .map(|s| s.value(0))
+                    .unwrap_or(false);
+                below_min || above_max
+            }
+            HudiOperator::Gt => gt_eq(&filter.values[0], max)
+                .map(|s| s.value(0))
+                .unwrap_or(false),
+            HudiOperator::Gte => gt(&filter.values[0], max)
+                .map(|s| s.value(0))
+                .unwrap_or(false),
+            HudiOperator::Lt => lt_eq(&filter.values[0], min)
+                .map(|s| s.value(0))
+                .unwrap_or(false),
+            HudiOperator::Lte => lt(&filter.values[0], min)
+                .map(|s| s.value(0))
+                .unwrap_or(false),
+            HudiOperator::Between => {
+                let (low, high) = (&filter.values[0], &filter.values[1]);
+                let low_above_max = gt(low, max).map(|s| s.value(0)).unwrap_or(false);
+                let high_below_min = lt(high, min).map(|s| s.value(0)).unwrap_or(false);
+                let low_above_high = gt(low, high).map(|s| s.value(0)).unwrap_or(false);
+                low_above_max || high_below_min || low_above_high
+            }
+            // Ne/In/NotIn/string-pattern operators aren't provable from a single min/max range.
+            _ => false,
+        }
+    }
+
+    /// Checks the direct `Leaf` children of an `And` group for a self-contradictory bound on
+    /// the same field (e.g. `count > 100 AND count < 10`), independent of any table stats.
+    ///
+    /// Tracks whether each bound is inclusive (`Gte`/`Lte`) or exclusive (`Gt`/`Lt`), since
+    /// `count >= 5 AND count <= 5` is satisfiable (by `count == 5`) while `count > 5 AND
+    /// count <= 5` is not, even though both reduce to the same boundary value.
+    fn and_leaves_contradict(exprs: &[PartitionExpr]) -> bool {
+        // (value, is_inclusive) per field.
+        let mut lowers: HashMap<&str, (&Scalar<ArrayRef>, bool)> = HashMap::new();
+        let mut uppers: HashMap<&str, (&Scalar<ArrayRef>, bool)> = HashMap::new();
+
+        for expr in exprs {
+            if let PartitionExpr::Leaf(filter) = expr {
+                let field_name = filter.field.name().as_str();
+                match filter.operator {
+                    HudiOperator::Gt | HudiOperator::Gte => {
+                        let bound = (&filter.values[0], filter.operator == HudiOperator::Gte);
+                        if lowers
+                            .get(field_name)
+                            .map(|current| Self::is_tighter_lower(bound, *current))
+                            .unwrap_or(true)
+                        {
+                            lowers.insert(field_name, bound);
+                        }
+                    }
+                    HudiOperator::Lt | HudiOperator::Lte => {
+                        let bound = (&filter.values[0], filter.operator == HudiOperator::Lte);
+                        if uppers
+                            .get(field_name)
+                            .map(|current| Self::is_tighter_upper(bound, *current))
+                            .unwrap_or(true)
+                        {
+                            uppers.insert(field_name, bound);
+                        }
+                    }
+                    _ => {}
+                }
             }
+        }
+
+        lowers.iter().any(|(field_name, lower)| {
+            uppers
+                .get(field_name)
+                .map(|upper| Self::bounds_contradict(*lower, *upper))
+                .unwrap_or(false)
         })
     }
 
+    /// Returns `true` if `new` is a strictly tighter lower bound than `current` (a higher
+    /// value, or the same value made exclusive).
+    fn is_tighter_lower(
+        new: (&Scalar<ArrayRef>, bool),
+        current: (&Scalar<ArrayRef>, bool),
+    ) -> bool {
+        let (new_value, new_inclusive) = new;
+        let (current_value, current_inclusive) = current;
+        if gt(new_value, current_value)
+            .map(|s| s.value(0))
+            .unwrap_or(false)
+        {
+            return true;
+        }
+        !new_inclusive
+            && current_inclusive
+            && eq(new_value, current_value)
+                .map(|s| s.value(0))
+                .unwrap_or(false)
+    }
+
+    /// Returns `true` if `new` is a strictly tighter upper bound than `current` (a lower
+    /// value, or the same value made exclusive).
+    fn is_tighter_upper(
+        new: (&Scalar<ArrayRef>, bool),
+        current: (&Scalar<ArrayRef>, bool),
+    ) -> bool {
+        let (new_value, new_inclusive) = new;
+        let (current_value, current_inclusive) = current;
+        if lt(new_value, current_value)
+            .map(|s| s.value(0))
+            .unwrap_or(false)
+        {
+            return true;
+        }
+        !new_inclusive
+            && current_inclusive
+            && eq(new_value, current_value)
+                .map(|s| s.value(0))
+                .unwrap_or(false)
+    }
+
+    /// Returns `true` if no value can satisfy both `lower` and `upper` simultaneously:
+    /// the lower bound exceeds the upper bound, or they meet at the same value with at
+    /// least one side exclusive.
+    fn bounds_contradict(
+        lower: (&Scalar<ArrayRef>, bool),
+        upper: (&Scalar<ArrayRef>, bool),
+    ) -> bool {
+        let (lower_value, lower_inclusive) = lower;
+        let (upper_value, upper_inclusive) = upper;
+        if gt(lower_value, upper_value)
+            .map(|s| s.value(0))
+            .unwrap_or(false)
+        {
+            return true;
+        }
+        (!lower_inclusive || !upper_inclusive)
+            && eq(lower_value, upper_value)
+                .map(|s| s.value(0))
+                .unwrap_or(false)
+    }
+
+    /// Reads a parsed segment value as an owned `String` (`None` if the segment is not a
+    /// `Utf8`/`LargeUtf8` value), for the string pattern operators that
+    /// compare directly against the segment rather than casting to a [Scalar].
+    fn segment_as_str(segment_value: &Scalar<ArrayRef>) -> Option<String> {
+        let array = segment_value.clone().into_inner();
+        array
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .map(|a| a.value(0).to_string())
+            .or_else(|| {
+                array
+                    .as_any()
+                    .downcast_ref::<LargeStringArray>()
+                    .map(|a| a.value(0).to_string())
+            })
+    }
+
     fn parse_segments(&self, partition_path: &str) -> Result<HashMap<String, Scalar<ArrayRef>>> {
         let partition_path = if self.is_url_encoded {
             percent_encoding::percent_decode(partition_path.as_bytes())
@@ -158,11 +588,23 @@ impl PartitionPruner {
 }
 
 /// A partition filter that represents a filter expression for partition pruning.
+///
+/// `values` holds the casted [Scalar]s: a single element for scalar operators (e.g.
+/// [HudiOperator::Eq]), one element per member for set operators (e.g. [HudiOperator::In]),
+/// a low/high pair for [HudiOperator::Between], and none for the string pattern operators,
+/// which use `raw_values` (and `regex`) instead.
 #[derive(Debug, Clone)]
 pub struct PartitionFilter {
     pub field: Field,
-    pub operator: ExprOperator,
-    pub value: Scalar<ArrayRef>,
+    pub operator: HudiOperator,
+    pub values: Vec<Scalar<ArrayRef>>,
+    /// The original, uncasted string value(s) the filter was built from, kept around so a
+    /// listing path prefix can be reconstructed without re-stringifying a casted [Scalar],
+    /// and so the string pattern operators (`StartsWith`, `Contains`) have a pattern to
+    /// compare against.
+    pub raw_values: Vec<String>,
+    /// The compiled pattern for [HudiOperator::Matches], `None` for every other operator.
+    pub regex: Option<Regex>,
 }
 
 impl TryFrom<(Filter, &Schema)> for PartitionFilter {
@@ -174,15 +616,82 @@ impl TryFrom<(Filter, &Schema)> for PartitionFilter {
             .map_err(|_| InvalidPartitionPath("Partition path should be in schema.".to_string()))?;
 
         let operator = filter.operator;
-        let value = &[filter.value.as_str()];
-        let value = Self::cast_value(value, field.data_type())
-            .map_err(|_| Unsupported(format!("Unable to cast {}.", field.data_type())))?;
+
+        if operator.is_string_pattern_operator() {
+            if !matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+                return Err(Unsupported(format!(
+                    "Operator {operator} only supports Utf8/LargeUtf8 fields, but {} is {}.",
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+            let FilterValue::Single(pattern) = &filter.value else {
+                return Err(Unsupported(format!(
+                    "Operator {operator} expects a single value."
+                )));
+            };
+            let regex = if operator == HudiOperator::Matches {
+                Some(Regex::new(pattern).map_err(|e| {
+                    Unsupported(format!("Invalid regex pattern '{pattern}': {e}"))
+                })?)
+            } else {
+                None
+            };
+            return Ok(PartitionFilter {
+                field: field.clone(),
+                operator,
+                values: Vec::new(),
+                raw_values: vec![pattern.clone()],
+                regex,
+            });
+        }
+
+        if operator.is_set_operator() {
+            if !matches!(&filter.value, FilterValue::Multiple(_)) {
+                return Err(Unsupported(format!(
+                    "Operator {operator} expects multiple values."
+                )));
+            }
+        } else if operator.is_range_operator() {
+            if !matches!(&filter.value, FilterValue::Range(_, _)) {
+                return Err(Unsupported(format!(
+                    "Operator {operator} expects a range of two values."
+                )));
+            }
+        } else if !matches!(&filter.value, FilterValue::Single(_)) {
+            return Err(Unsupported(format!(
+                "Operator {operator} expects a single value."
+            )));
+        }
+
+        let (values, raw_values) = match &filter.value {
+            FilterValue::Single(value) => {
+                let values = vec![Self::cast_value(&[value.as_str()], field.data_type())
+                    .map_err(|_| Unsupported(format!("Unable to cast {}.", field.data_type())))?];
+                (values, vec![value.clone()])
+            }
+            FilterValue::Multiple(values) => {
+                let str_values: Vec<&str> = values.iter().map(String::as_str).collect();
+                let casted = Self::cast_values(&str_values, field.data_type())
+                    .map_err(|_| Unsupported(format!("Unable to cast {}.", field.data_type())))?;
+                (casted, values.clone())
+            }
+            FilterValue::Range(low, high) => {
+                let low_scalar = Self::cast_value(&[low.as_str()], field.data_type())
+                    .map_err(|_| Unsupported(format!("Unable to cast {}.", field.data_type())))?;
+                let high_scalar = Self::cast_value(&[high.as_str()], field.data_type())
+                    .map_err(|_| Unsupported(format!("Unable to cast {}.", field.data_type())))?;
+                (vec![low_scalar, high_scalar], vec![low.clone(), high.clone()])
+            }
+        };
 
         let field = field.clone();
         Ok(PartitionFilter {
             field,
             operator,
-            value,
+            values,
+            raw_values,
+            regex: None,
         })
     }
 }
@@ -202,6 +711,22 @@ impl PartitionFilter {
             &cast_options,
         )?))
     }
+
+    /// Casts a slice of string values into a single arrow array and returns one [Scalar] per
+    /// element, for use with set-membership operators like `IN` / `NOT IN`.
+    pub fn cast_values(values: &[&str], data_type: &DataType) -> Result<Vec<Scalar<ArrayRef>>> {
+        let cast_options = CastOptions {
+            safe: false,
+            format_options: Default::default(),
+        };
+
+        let array = StringArray::from(values.to_vec());
+        let casted: ArrayRef = cast_with_options(&array, data_type, &cast_options)?;
+
+        Ok((0..casted.len())
+            .map(|i| Scalar::new(casted.slice(i, 1)))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -242,7 +767,12 @@ mod tests {
         assert!(pruner.is_ok());
 
         let pruner = pruner.unwrap();
-        assert_eq!(pruner.and_filters.len(), 2);
+        match &pruner.expr {
+            PartitionExpr::And(leaves) => {
+                assert_eq!(leaves.len(), 2);
+            }
+            other => panic!("expected a conjunction, got {other:?}"),
+        }
         assert!(pruner.is_hive_style);
         assert_not!(pruner.is_url_encoded);
     }
@@ -341,15 +871,16 @@ mod tests {
         let schema = create_test_schema();
         let filter = Filter {
             field_name: "date".to_string(),
-            operator: ExprOperator::Eq,
-            value: "2023-01-01".to_string(),
+            operator: HudiOperator::Eq,
+            value: FilterValue::Single("2023-01-01".to_string()),
         };
 
         let partition_filter = PartitionFilter::try_from((filter, &schema)).unwrap();
         assert_eq!(partition_filter.field.name(), "date");
-        assert_eq!(partition_filter.operator, ExprOperator::Eq);
+        assert_eq!(partition_filter.operator, HudiOperator::Eq);
+        assert_eq!(partition_filter.values.len(), 1);
 
-        let value_inner = partition_filter.value.into_inner();
+        let value_inner = partition_filter.values[0].clone().into_inner();
 
         let date_array = value_inner.as_any().downcast_ref::<Date32Array>().unwrap();
 
@@ -362,8 +893,8 @@ mod tests {
         let schema = create_test_schema();
         let filter = Filter {
             field_name: "invalid_field".to_string(),
-            operator: ExprOperator::Eq,
-            value: "2023-01-01".to_string(),
+            operator: HudiOperator::Eq,
+            value: FilterValue::Single("2023-01-01".to_string()),
         };
         let result = PartitionFilter::try_from((filter, &schema));
         assert!(result.is_err());
@@ -378,8 +909,8 @@ mod tests {
         let schema = create_test_schema();
         let filter = Filter {
             field_name: "count".to_string(),
-            operator: ExprOperator::Eq,
-            value: "not_a_number".to_string(),
+            operator: HudiOperator::Eq,
+            value: FilterValue::Single("not_a_number".to_string()),
         };
         let result = PartitionFilter::try_from((filter, &schema));
         assert!(result.is_err());
@@ -388,16 +919,504 @@ mod tests {
     #[test]
     fn test_partition_filter_try_from_all_operators() {
         let schema = create_test_schema();
-        for (op, _) in ExprOperator::TOKEN_OP_PAIRS {
+        for (op, operator) in HudiOperator::TOKEN_OP_PAIRS {
+            // String pattern operators only apply to Utf8/LargeUtf8 fields, and set/range
+            // operators expect a `Multiple`/`Range` value rather than `Single`; all are
+            // covered by their own dedicated tests.
+            if operator.is_string_pattern_operator()
+                || operator.is_set_operator()
+                || operator.is_range_operator()
+            {
+                continue;
+            }
             let filter = Filter {
                 field_name: "count".to_string(),
-                operator: ExprOperator::from_str(op).unwrap(),
-                value: "5".to_string(),
+                operator,
+                value: FilterValue::Single("5".to_string()),
             };
             let partition_filter = PartitionFilter::try_from((filter, &schema));
             let filter = partition_filter.unwrap();
             assert_eq!(filter.field.name(), "count");
-            assert_eq!(filter.operator, ExprOperator::from_str(op).unwrap());
+            assert_eq!(filter.operator, HudiOperator::from_str(op).unwrap());
         }
     }
+
+    #[test]
+    fn test_partition_filter_try_from_multiple_values() {
+        let schema = create_test_schema();
+        let filter = Filter {
+            field_name: "category".to_string(),
+            operator: HudiOperator::In,
+            value: FilterValue::Multiple(vec!["A".to_string(), "B".to_string(), "C".to_string()]),
+        };
+
+        let partition_filter = PartitionFilter::try_from((filter, &schema)).unwrap();
+        assert_eq!(partition_filter.operator, HudiOperator::In);
+        assert_eq!(partition_filter.values.len(), 3);
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_range_value() {
+        let schema = create_test_schema();
+        let filter = Filter {
+            field_name: "count".to_string(),
+            operator: HudiOperator::Between,
+            value: FilterValue::Range("10".to_string(), "100".to_string()),
+        };
+
+        let partition_filter = PartitionFilter::try_from((filter, &schema)).unwrap();
+        assert_eq!(partition_filter.operator, HudiOperator::Between);
+        assert_eq!(partition_filter.values.len(), 2);
+        assert_eq!(partition_filter.raw_values, vec!["10", "100"]);
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_rejects_single_value_for_range_operator() {
+        let schema = create_test_schema();
+        let filter = Filter {
+            field_name: "count".to_string(),
+            operator: HudiOperator::Between,
+            value: FilterValue::Single("10".to_string()),
+        };
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_rejects_single_value_for_set_operator() {
+        let schema = create_test_schema();
+        let filter = Filter {
+            field_name: "category".to_string(),
+            operator: HudiOperator::In,
+            value: FilterValue::Single("A".to_string()),
+        };
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_rejects_non_single_value_for_scalar_operator() {
+        let schema = create_test_schema();
+        let filter = Filter {
+            field_name: "count".to_string(),
+            operator: HudiOperator::Eq,
+            value: FilterValue::Multiple(vec!["5".to_string(), "6".to_string()]),
+        };
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+
+        let filter = Filter {
+            field_name: "count".to_string(),
+            operator: HudiOperator::Eq,
+            value: FilterValue::Range("5".to_string(), "6".to_string()),
+        };
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_between() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter_between = Filter::try_from(("count", "BETWEEN", "10", "100")).unwrap();
+        let pruner = PartitionPruner::new(&[filter_between], &schema, &configs).unwrap();
+
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=100"));
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=50"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=A/count=9"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=A/count=101"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_in_not_in() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let values = ["A", "B"];
+        let filter_in = Filter::try_from(("category", "IN", values.as_slice())).unwrap();
+        let pruner_in = PartitionPruner::new(&[filter_in], &schema, &configs).unwrap();
+        assert!(pruner_in.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner_in.should_include("date=2023-02-01/category=B/count=10"));
+        assert_not!(pruner_in.should_include("date=2023-02-01/category=C/count=10"));
+
+        let filter_not_in = Filter::try_from(("category", "NOT IN", values.as_slice())).unwrap();
+        let pruner_not_in = PartitionPruner::new(&[filter_not_in], &schema, &configs).unwrap();
+        assert_not!(pruner_not_in.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner_not_in.should_include("date=2023-02-01/category=C/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_hive_style() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter_eq_date = Filter::try_from(("date", "=", "2023-02-01")).unwrap();
+        let filter_eq_category = Filter::try_from(("category", "=", "A")).unwrap();
+        let pruner = PartitionPruner::new(
+            &[filter_eq_date, filter_eq_category],
+            &schema,
+            &configs,
+        )
+        .unwrap();
+
+        assert_eq!(
+            pruner.listing_prefix().unwrap(),
+            "date=2023-02-01/category=A"
+        );
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_non_hive_style() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(false, false);
+
+        let filter_eq_date = Filter::try_from(("date", "=", "2023-02-01")).unwrap();
+        let pruner = PartitionPruner::new(&[filter_eq_date], &schema, &configs).unwrap();
+
+        assert_eq!(pruner.listing_prefix().unwrap(), "2023-02-01");
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_stops_at_gap() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter_eq_date = Filter::try_from(("date", "=", "2023-02-01")).unwrap();
+        let filter_gt_count = Filter::try_from(("count", ">", "10")).unwrap();
+        let pruner =
+            PartitionPruner::new(&[filter_eq_date, filter_gt_count], &schema, &configs).unwrap();
+
+        // `category` has no `Eq` filter, so the prefix stops after `date`.
+        assert_eq!(pruner.listing_prefix().unwrap(), "date=2023-02-01");
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_none_without_leading_eq() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter_gt_date = Filter::try_from(("date", ">", "2023-01-01")).unwrap();
+        let pruner = PartitionPruner::new(&[filter_gt_date], &schema, &configs).unwrap();
+
+        assert!(pruner.listing_prefix().is_none());
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_url_encoded() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, true);
+
+        let filter_eq_category = Filter::try_from(("category", "=", "A/B")).unwrap();
+        let pruner = PartitionPruner::new(&[filter_eq_category.clone()], &schema, &configs);
+        // `category` is the 2nd field but `date` is first and has no filter, so prefix is None.
+        assert!(pruner.unwrap().listing_prefix().is_none());
+
+        // With a leading `date` equality, the `category` value is percent-encoded.
+        let filter_eq_date = Filter::try_from(("date", "=", "2023-02-01")).unwrap();
+        let pruner =
+            PartitionPruner::new(&[filter_eq_date, filter_eq_category], &schema, &configs).unwrap();
+        assert_eq!(
+            pruner.listing_prefix().unwrap(),
+            "date=2023-02-01/category=A%2FB"
+        );
+    }
+
+    fn leaf(field_name: &str, op: &str, value: &str, schema: &Schema) -> PartitionExpr {
+        let filter = Filter::try_from((field_name, op, value)).unwrap();
+        PartitionExpr::Leaf(PartitionFilter::try_from((filter, schema)).unwrap())
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_or_expr() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // date > 2023-02-15 OR category = 'A'
+        let expr = PartitionExpr::Or(vec![
+            leaf("date", ">", "2023-02-15", &schema),
+            leaf("category", "=", "A", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner.should_include("date=2023-03-01/category=B/count=10"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=B/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_not_expr() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // NOT (category = 'A')
+        let expr = PartitionExpr::Not(Box::new(leaf("category", "=", "A", &schema)));
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert_not!(pruner.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner.should_include("date=2023-02-01/category=B/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_not_undecided_leaf_stays_included() {
+        // Simulates a PartitionExpr built against a schema that doesn't match the one the
+        // pruner parses segments with (e.g. a stale cache), so the leaf's field is absent
+        // from the parsed segments and its own evaluation is undecided ("include").
+        let leaf_schema = Schema::new(vec![Field::new("region", DataType::Utf8, false)]);
+        let expr = PartitionExpr::Not(Box::new(leaf("region", "=", "APAC", &leaf_schema)));
+
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        // An undecided leaf must stay "include" even after negation, not flip to "exclude".
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_nested_and_or() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // date > 2023-01-01 AND (category = 'A' OR category = 'B')
+        let expr = PartitionExpr::And(vec![
+            leaf("date", ">", "2023-01-01", &schema),
+            PartitionExpr::Or(vec![
+                leaf("category", "=", "A", &schema),
+                leaf("category", "=", "B", &schema),
+            ]),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.should_include("date=2023-02-01/category=A/count=10"));
+        assert!(pruner.should_include("date=2023-02-01/category=B/count=10"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=C/count=10"));
+        assert_not!(pruner.should_include("date=2022-12-31/category=A/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_listing_prefix_none_for_or_expr() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let expr = PartitionExpr::Or(vec![
+            leaf("date", "=", "2023-02-01", &schema),
+            leaf("category", "=", "A", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.listing_prefix().is_none());
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_starts_with() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("category", "STARTSWITH", "A")).unwrap();
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs).unwrap();
+
+        assert!(pruner.should_include("date=2023-02-01/category=APAC/count=10"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=EMEA/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_contains() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("category", "CONTAINS", "PA")).unwrap();
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs).unwrap();
+
+        assert!(pruner.should_include("date=2023-02-01/category=APAC/count=10"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=EMEA/count=10"));
+    }
+
+    #[test]
+    fn test_partition_pruner_should_include_matches() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("category", "MATCHES", "^A.*C$")).unwrap();
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs).unwrap();
+
+        assert!(pruner.should_include("date=2023-02-01/category=APAC/count=10"));
+        assert_not!(pruner.should_include("date=2023-02-01/category=EMEA/count=10"));
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_string_pattern_rejects_non_string_field() {
+        let schema = create_test_schema();
+        let filter = Filter::try_from(("count", "STARTSWITH", "1")).unwrap();
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_partition_filter_try_from_matches_rejects_invalid_regex() {
+        let schema = create_test_schema();
+        let filter = Filter::try_from(("category", "MATCHES", "(")).unwrap();
+        let result = PartitionFilter::try_from((filter, &schema));
+        assert!(result.is_err());
+    }
+
+    fn scalar(field_name: &str, value: &str, schema: &Schema) -> Scalar<ArrayRef> {
+        let filter = Filter::try_from((field_name, "=", value)).unwrap();
+        PartitionFilter::try_from((filter, schema)).unwrap().values[0].clone()
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_eq_outside_bounds() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("count", "=", "500")).unwrap();
+        let stats = HashMap::from([(
+            "count".to_string(),
+            (scalar("count", "0", &schema), scalar("count", "100", &schema)),
+        )]);
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs)
+            .unwrap()
+            .with_stats(stats);
+
+        assert!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_eq_within_bounds() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("count", "=", "50")).unwrap();
+        let stats = HashMap::from([(
+            "count".to_string(),
+            (scalar("count", "0", &schema), scalar("count", "100", &schema)),
+        )]);
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs)
+            .unwrap()
+            .with_stats(stats);
+
+        assert_not!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_conservative_without_stats() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("count", "=", "500")).unwrap();
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs).unwrap();
+
+        assert_not!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_between_contradiction() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let filter = Filter::try_from(("count", "BETWEEN", "100", "10")).unwrap();
+        let pruner = PartitionPruner::new(&[filter], &schema, &configs).unwrap();
+
+        assert!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_and_contradicting_bounds() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // count > 100 AND count < 10
+        let expr = PartitionExpr::And(vec![
+            leaf("count", ">", "100", &schema),
+            leaf("count", "<", "10", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_and_tightest_bound_wins() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // count > 5 AND count > 100 AND count < 10: the tightest lower bound (100) still
+        // contradicts the upper bound (10), even though it isn't the first `Gt` leaf seen.
+        let expr = PartitionExpr::And(vec![
+            leaf("count", ">", "5", &schema),
+            leaf("count", ">", "100", &schema),
+            leaf("count", "<", "10", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_and_inclusive_equal_bounds_is_satisfiable() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // count >= 5 AND count <= 5: satisfiable by count == 5, unlike the strict case.
+        let expr = PartitionExpr::And(vec![
+            leaf("count", ">=", "5", &schema),
+            leaf("count", "<=", "5", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert_not!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_and_exclusive_equal_bound_contradicts() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        // count > 5 AND count <= 5: no value satisfies both.
+        let expr = PartitionExpr::And(vec![
+            leaf("count", ">", "5", &schema),
+            leaf("count", "<=", "5", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs);
+
+        assert!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_or_requires_all_branches_unsatisfiable() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let stats = HashMap::from([(
+            "count".to_string(),
+            (scalar("count", "0", &schema), scalar("count", "100", &schema)),
+        )]);
+
+        // (count = 500) OR (count = 50): only one branch is provably impossible.
+        let expr = PartitionExpr::Or(vec![
+            leaf("count", "=", "500", &schema),
+            leaf("count", "=", "50", &schema),
+        ]);
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs).with_stats(stats);
+
+        assert_not!(pruner.is_unsatisfiable());
+    }
+
+    #[test]
+    fn test_is_unsatisfiable_not_is_conservative() {
+        let schema = create_test_schema();
+        let configs = create_hudi_configs(true, false);
+
+        let stats = HashMap::from([(
+            "count".to_string(),
+            (scalar("count", "0", &schema), scalar("count", "100", &schema)),
+        )]);
+
+        let expr = PartitionExpr::Not(Box::new(leaf("count", "=", "500", &schema)));
+        let pruner = PartitionPruner::new_with_expr(expr, &schema, &configs).with_stats(stats);
+
+        assert_not!(pruner.is_unsatisfiable());
+    }
 }